@@ -1,26 +1,307 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
 
-struct Point {
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Point<T> {
+    x: T,
+    y: T,
+}
+
+impl<T> Point<T> {
+    fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    /// Builds a `Point<T>` by taking `x` from `self` and `y` from `other`,
+    /// useful when combining two points of the same scalar type.
+    fn mixup(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x, other.y)
+    }
+}
+
+impl Point<i32> {
+    fn from_i32(x: i32, y: i32) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl Point<f64> {
+    const ORIGIN: Point<f64> = Point { x: 0.0, y: 0.0 };
+
+    fn distance(&self) -> f64 {
+        self.distance_to(&Point::ORIGIN)
+    }
+
+    fn distance_to(&self, other: &Point<f64>) -> f64 {
+        self.distance_squared_to(other).sqrt()
+    }
+
+    fn distance_squared_to(&self, other: &Point<f64>) -> f64 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        dx * dx + dy * dy
+    }
+
+    fn to_vec2(self) -> Vec2 {
+        Vec2::new(self.x, self.y)
+    }
+
+    fn lerp(self, other: Point<f64>, t: f64) -> Point<f64> {
+        self + (other - self) * t
+    }
+}
+
+fn distance_between(a: &Point<f64>, b: &Point<f64>) -> f64 {
+    a.distance_to(b)
+}
+
+impl Add<Vec2> for Point<f64> {
+    type Output = Point<f64>;
+
+    fn add(self, rhs: Vec2) -> Point<f64> {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign<Vec2> for Point<f64> {
+    fn add_assign(&mut self, rhs: Vec2) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl Sub<Point<f64>> for Point<f64> {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Point<f64>) -> Vec2 {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign<Vec2> for Point<f64> {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Vec2 {
     x: f64,
     y: f64,
 }
 
-impl Point {
+impl Vec2 {
+    const ZERO: Vec2 = Vec2 { x: 0.0, y: 0.0 };
+
     fn new(x: f64, y: f64) -> Self {
-        Point { x, y }
+        Vec2 { x, y }
     }
+}
 
-    fn distance(&self) -> f64 {
-        (self.x * self.x + self.y * self.y).sqrt()
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Vec2) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl std::ops::Mul<f64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: f64) -> Vec2 {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Rect {
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+}
+
+impl Rect {
+    fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Rect {
+            x1: x,
+            y1: y,
+            x2: x + width,
+            y2: y + height,
+        }
+    }
+
+    fn width(&self) -> f64 {
+        self.x2 - self.x1
+    }
+
+    fn height(&self) -> f64 {
+        self.y2 - self.y1
+    }
+
+    fn intersect(&self, other: &Rect) -> bool {
+        self.x1 <= other.x2 && self.x2 >= other.x1 && self.y1 <= other.y2 && self.y2 >= other.y1
+    }
+
+    fn contains(&self, p: &Point<f64>) -> bool {
+        p.x >= self.x1 && p.x <= self.x2 && p.y >= self.y1 && p.y <= self.y2
+    }
+
+    fn center(&self) -> Point<f64> {
+        Point::new((self.x1 + self.x2) / 2.0, (self.y1 + self.y2) / 2.0)
     }
 }
 
 trait Shape {
     fn area(&self) -> f64;
+
+    fn bounding_box(&self) -> Rect;
+
+    /// Falls back to the perimeter of the bounding box when a shape has no
+    /// exact formula of its own; override this for anything where that
+    /// would be a poor approximation (e.g. `Circle`, `Triangle`).
+    fn perimeter(&self) -> f64 {
+        let b = self.bounding_box();
+        2.0 * (b.width() + b.height())
+    }
+}
+
+struct Circle {
+    center: Point<f64>,
+    radius: f64,
+}
+
+impl Circle {
+    fn new(center: Point<f64>, radius: f64) -> Self {
+        Circle { center, radius }
+    }
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * std::f64::consts::PI * self.radius
+    }
+
+    fn bounding_box(&self) -> Rect {
+        Rect::new(
+            self.center.x - self.radius,
+            self.center.y - self.radius,
+            self.radius * 2.0,
+            self.radius * 2.0,
+        )
+    }
+}
+
+struct Rectangle {
+    origin: Point<f64>,
+    width: f64,
+    height: f64,
+}
+
+impl Rectangle {
+    fn new(origin: Point<f64>, width: f64, height: f64) -> Self {
+        Rectangle {
+            origin,
+            width,
+            height,
+        }
+    }
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn bounding_box(&self) -> Rect {
+        Rect::new(self.origin.x, self.origin.y, self.width, self.height)
+    }
+}
+
+struct Triangle {
+    a: Point<f64>,
+    b: Point<f64>,
+    c: Point<f64>,
+}
+
+impl Triangle {
+    fn new(a: Point<f64>, b: Point<f64>, c: Point<f64>) -> Self {
+        Triangle { a, b, c }
+    }
+
+    fn side_lengths(&self) -> (f64, f64, f64) {
+        let ab = self.a - self.b;
+        let bc = self.b - self.c;
+        let ca = self.c - self.a;
+        (
+            (ab.x * ab.x + ab.y * ab.y).sqrt(),
+            (bc.x * bc.x + bc.y * bc.y).sqrt(),
+            (ca.x * ca.x + ca.y * ca.y).sqrt(),
+        )
+    }
+}
+
+impl Shape for Triangle {
+    fn area(&self) -> f64 {
+        ((self.a.x * (self.b.y - self.c.y)
+            + self.b.x * (self.c.y - self.a.y)
+            + self.c.x * (self.a.y - self.b.y))
+            / 2.0)
+            .abs()
+    }
+
+    fn perimeter(&self) -> f64 {
+        let (ab, bc, ca) = self.side_lengths();
+        ab + bc + ca
+    }
+
+    fn bounding_box(&self) -> Rect {
+        let min_x = self.a.x.min(self.b.x).min(self.c.x);
+        let max_x = self.a.x.max(self.b.x).max(self.c.x);
+        let min_y = self.a.y.min(self.b.y).min(self.c.y);
+        let max_y = self.a.y.max(self.b.y).max(self.c.y);
+        Rect::new(min_x, min_y, max_x - min_x, max_y - min_y)
+    }
 }
 
 fn main() {
     let p = Point::new(3.0, 4.0);
     println!("Distance: {}", p.distance());
+
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(Circle::new(Point::new(0.0, 0.0), 2.0)),
+        Box::new(Rectangle::new(Point::new(0.0, 0.0), 3.0, 4.0)),
+    ];
+    for shape in &shapes {
+        println!("area: {}, perimeter: {}", shape.area(), shape.perimeter());
+    }
 }
 
 #[cfg(test)]
@@ -32,4 +313,142 @@ mod tests {
         let p = Point::new(3.0, 4.0);
         assert_eq!(p.distance(), 5.0);
     }
+
+    #[test]
+    fn test_rect_overlapping() {
+        let a = Rect::new(0.0, 0.0, 4.0, 4.0);
+        let b = Rect::new(2.0, 2.0, 4.0, 4.0);
+        assert!(a.intersect(&b));
+    }
+
+    #[test]
+    fn test_rect_edge_touching() {
+        let a = Rect::new(0.0, 0.0, 4.0, 4.0);
+        let b = Rect::new(4.0, 0.0, 4.0, 4.0);
+        assert!(a.intersect(&b));
+    }
+
+    #[test]
+    fn test_rect_disjoint() {
+        let a = Rect::new(0.0, 0.0, 4.0, 4.0);
+        let b = Rect::new(5.0, 5.0, 4.0, 4.0);
+        assert!(!a.intersect(&b));
+    }
+
+    #[test]
+    fn test_rect_contains() {
+        let r = Rect::new(0.0, 0.0, 4.0, 4.0);
+        assert!(r.contains(&Point::new(2.0, 2.0)));
+        assert!(!r.contains(&Point::new(5.0, 5.0)));
+    }
+
+    #[test]
+    fn test_rect_center_and_dims() {
+        let r = Rect::new(0.0, 0.0, 4.0, 2.0);
+        assert_eq!(r.center(), Point::new(2.0, 1.0));
+        assert_eq!(r.width(), 4.0);
+        assert_eq!(r.height(), 2.0);
+    }
+
+    #[test]
+    fn test_point_sub_yields_vec2() {
+        let a = Point::new(3.0, 4.0);
+        let b = Point::new(1.0, 1.0);
+        assert_eq!(a - b, Vec2::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn test_point_add_vec2() {
+        let p = Point::new(1.0, 1.0);
+        let v = Vec2::new(2.0, 3.0);
+        assert_eq!(p + v, Point::new(3.0, 4.0));
+    }
+
+    #[test]
+    fn test_to_vec2() {
+        let p = Point::new(3.0, 4.0);
+        assert_eq!(p.to_vec2(), Vec2::new(3.0, 4.0));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(10.0, 20.0);
+        assert_eq!(a.lerp(b, 0.5), Point::new(5.0, 10.0));
+    }
+
+    #[test]
+    fn test_origin_and_zero() {
+        assert_eq!(Point::ORIGIN, Point::new(0.0, 0.0));
+        assert_eq!(Vec2::ZERO, Vec2::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_generic_integer_point() {
+        let p = Point::from_i32(3, 4);
+        assert_eq!(p, Point::new(3, 4));
+    }
+
+    #[test]
+    fn test_mixup() {
+        let a = Point::new(1, 2);
+        let b = Point::new(3, 4);
+        assert_eq!(a.mixup(b), Point::new(1, 4));
+    }
+
+    #[test]
+    fn test_circle_area_and_perimeter() {
+        let c = Circle::new(Point::new(0.0, 0.0), 2.0);
+        assert!((c.area() - std::f64::consts::PI * 4.0).abs() < 1e-9);
+        assert!((c.perimeter() - std::f64::consts::PI * 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rectangle_shape_area_and_perimeter() {
+        let r = Rectangle::new(Point::new(0.0, 0.0), 3.0, 4.0);
+        assert_eq!(r.area(), 12.0);
+        assert_eq!(r.perimeter(), 14.0);
+    }
+
+    #[test]
+    fn test_triangle_area_and_perimeter() {
+        let t = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        );
+        assert_eq!(t.area(), 6.0);
+        assert_eq!(t.perimeter(), 12.0);
+    }
+
+    #[test]
+    fn test_distance_to() {
+        let a = Point::new(3.0, 4.0);
+        let b = Point::new(0.0, 0.0);
+        assert_eq!(a.distance_to(&b), 5.0);
+    }
+
+    #[test]
+    fn test_distance_squared_to() {
+        let a = Point::new(3.0, 4.0);
+        let b = Point::new(0.0, 0.0);
+        assert_eq!(a.distance_squared_to(&b), 25.0);
+    }
+
+    #[test]
+    fn test_distance_between() {
+        let a = Point::new(3.0, 4.0);
+        let b = Point::new(0.0, 0.0);
+        assert_eq!(distance_between(&a, &b), 5.0);
+    }
+
+    #[test]
+    fn test_bounding_box_via_trait_object() {
+        let shapes: Vec<Box<dyn Shape>> = vec![
+            Box::new(Circle::new(Point::new(1.0, 1.0), 1.0)),
+            Box::new(Rectangle::new(Point::new(0.0, 0.0), 2.0, 2.0)),
+        ];
+        assert_eq!(shapes[0].bounding_box(), Rect::new(0.0, 0.0, 2.0, 2.0));
+        assert_eq!(shapes[1].bounding_box(), Rect::new(0.0, 0.0, 2.0, 2.0));
+    }
 }